@@ -21,6 +21,10 @@ use tokio_util::codec::{length_delimited::LengthDelimitedCodec, Framed};
 pub struct Transport<S, Item, SinkItem, Codec> {
     #[pin]
     inner: SerdeFramed<Framed<S, LengthDelimitedCodec>, Item, SinkItem, Codec>,
+    // Set once any `Stream`/`Sink` operation observes an error, so that a corrupted or
+    // partially-written frame can't be retried against the inner codec. Once poisoned, every
+    // subsequent operation short-circuits with a stable error instead of touching `inner`.
+    poisoned: bool,
 }
 
 impl<S, Item, SinkItem, Codec> Transport<S, Item, SinkItem, Codec> {
@@ -30,6 +34,14 @@ impl<S, Item, SinkItem, Codec> Transport<S, Item, SinkItem, Codec> {
     }
 }
 
+impl<S, Item, SinkItem, Codec> Transport<S, Item, SinkItem, Codec> {
+    /// Returns a [`Builder`] for configuring framing and buffering before constructing a
+    /// `Transport`.
+    pub fn builder() -> Builder {
+        Builder::new()
+    }
+}
+
 impl<S, Item, SinkItem, Codec, CodecError> Stream for Transport<S, Item, SinkItem, Codec>
 where
     S: AsyncWrite + AsyncRead,
@@ -42,77 +54,1084 @@ where
     type Item = io::Result<Item>;
 
     fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<io::Result<Item>>> {
-        match self.project().inner.poll_next(cx) {
+        let mut this = self.project();
+        if *this.poisoned {
+            return Poll::Ready(Some(Err(poisoned_error())));
+        }
+        match this.inner.as_mut().poll_next(cx) {
             Poll::Pending => Poll::Pending,
             Poll::Ready(None) => Poll::Ready(None),
             Poll::Ready(Some(Ok::<_, CodecError>(next))) => Poll::Ready(Some(Ok(next))),
             Poll::Ready(Some(Err::<_, CodecError>(e))) => {
+                *this.poisoned = true;
                 Poll::Ready(Some(Err(io::Error::new(io::ErrorKind::Other, e))))
             }
         }
     }
-}
+}
+
+impl<S, Item, SinkItem, Codec, CodecError> Sink<SinkItem> for Transport<S, Item, SinkItem, Codec>
+where
+    S: AsyncWrite,
+    SinkItem: Serialize,
+    Codec: Serializer<SinkItem>,
+    CodecError: Into<Box<dyn Error + Send + Sync>>,
+    SerdeFramed<Framed<S, LengthDelimitedCodec>, Item, SinkItem, Codec>:
+        Sink<SinkItem, Error = CodecError>,
+{
+    type Error = io::Error;
+
+    fn poll_ready(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        let this = self.project();
+        if *this.poisoned {
+            return Poll::Ready(Err(poisoned_error()));
+        }
+        convert(this.poisoned, this.inner.poll_ready(cx))
+    }
+
+    fn start_send(self: Pin<&mut Self>, item: SinkItem) -> io::Result<()> {
+        let this = self.project();
+        if *this.poisoned {
+            return Err(poisoned_error());
+        }
+        this.inner.start_send(item).map_err(|e| {
+            *this.poisoned = true;
+            io::Error::new(io::ErrorKind::Other, e)
+        })
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        let this = self.project();
+        if *this.poisoned {
+            return Poll::Ready(Err(poisoned_error()));
+        }
+        convert(this.poisoned, this.inner.poll_flush(cx))
+    }
+
+    fn poll_close(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        let this = self.project();
+        if *this.poisoned {
+            return Poll::Ready(Err(poisoned_error()));
+        }
+        convert(this.poisoned, this.inner.poll_close(cx))
+    }
+}
+
+fn convert<E: Into<Box<dyn Error + Send + Sync>>>(
+    poisoned: &mut bool,
+    poll: Poll<Result<(), E>>,
+) -> Poll<io::Result<()>> {
+    poll.map(|ready| {
+        ready.map_err(|e| {
+            *poisoned = true;
+            io::Error::new(io::ErrorKind::Other, e)
+        })
+    })
+}
+
+/// Returns the stable error a [`Transport`] yields for every operation once it has been
+/// poisoned by a prior error, instead of risking partial-frame corruption by retrying the
+/// inner codec.
+fn poisoned_error() -> io::Error {
+    io::Error::new(io::ErrorKind::Other, "transport poisoned by previous error")
+}
+
+impl<S, Item, SinkItem, Codec> From<(S, Codec)> for Transport<S, Item, SinkItem, Codec>
+where
+    S: AsyncWrite + AsyncRead,
+    Item: for<'de> Deserialize<'de>,
+    SinkItem: Serialize,
+    Codec: Serializer<SinkItem> + Deserializer<Item>,
+{
+    fn from((inner, codec): (S, Codec)) -> Self {
+        Builder::new().build(inner, codec)
+    }
+}
+
+/// Configures the length-delimited framing and buffering of a [`Transport`]'s underlying byte
+/// stream. Defaults match [`LengthDelimitedCodec::new()`]: an 8 MiB default frame-length cap, a
+/// 4-byte big-endian length field at offset 0, and the `Framed` default read buffer capacity.
+#[derive(Clone, Debug)]
+pub struct Builder {
+    codec_builder: tokio_util::codec::length_delimited::Builder,
+    read_buffer_capacity: usize,
+}
+
+impl Default for Builder {
+    fn default() -> Self {
+        Builder {
+            codec_builder: LengthDelimitedCodec::builder(),
+            read_buffer_capacity: 8 * 1024,
+        }
+    }
+}
+
+impl Builder {
+    /// Returns a new `Builder` with the same defaults as [`LengthDelimitedCodec::new()`].
+    pub fn new() -> Self {
+        Builder::default()
+    }
+
+    /// Sets the maximum length, in bytes, of a single frame. Frames larger than this are
+    /// rejected with an error instead of being buffered, so a misbehaving or malicious peer can't
+    /// force unbounded memory growth by sending an oversized length prefix.
+    pub fn max_frame_length(&mut self, val: usize) -> &mut Self {
+        self.codec_builder.max_frame_length(val);
+        self
+    }
+
+    /// Sets the number of bytes used to encode the frame length field (default 4).
+    pub fn length_field_length(&mut self, val: usize) -> &mut Self {
+        self.codec_builder.length_field_length(val);
+        self
+    }
+
+    /// Sets the number of bytes to skip before the length field begins (default 0).
+    pub fn length_field_offset(&mut self, val: usize) -> &mut Self {
+        self.codec_builder.length_field_offset(val);
+        self
+    }
+
+    /// Sets the initial capacity, in bytes, of the transport's internal read buffer.
+    pub fn read_buffer_capacity(&mut self, val: usize) -> &mut Self {
+        self.read_buffer_capacity = val;
+        self
+    }
+
+    /// Builds a [`Transport`] that reads from and writes to `io`, using the framing and buffering
+    /// configured on this builder.
+    pub fn build<S, Item, SinkItem, Codec>(
+        &self,
+        io: S,
+        codec: Codec,
+    ) -> Transport<S, Item, SinkItem, Codec>
+    where
+        S: AsyncWrite + AsyncRead,
+        Item: for<'de> Deserialize<'de>,
+        SinkItem: Serialize,
+        Codec: Serializer<SinkItem> + Deserializer<Item>,
+    {
+        let framed = Framed::with_capacity(
+            io,
+            self.codec_builder.new_codec(),
+            self.read_buffer_capacity,
+        );
+        Transport {
+            inner: SerdeFramed::new(framed, codec),
+            poisoned: false,
+        }
+    }
+}
+
+#[cfg(feature = "tcp")]
+#[cfg_attr(docsrs, doc(cfg(feature = "tcp")))]
+/// TCP support for generic transport using Tokio.
+pub mod tcp {
+    use {
+        super::*,
+        futures::ready,
+        std::{marker::PhantomData, net::SocketAddr},
+        tokio::net::{TcpListener, TcpStream, ToSocketAddrs},
+    };
+
+    mod private {
+        use super::*;
+
+        pub trait Sealed {}
+
+        impl<Item, SinkItem, Codec> Sealed for Transport<TcpStream, Item, SinkItem, Codec> {}
+    }
+
+    impl<Item, SinkItem, Codec> Transport<TcpStream, Item, SinkItem, Codec> {
+        /// Returns the peer address of the underlying TcpStream.
+        pub fn peer_addr(&self) -> io::Result<SocketAddr> {
+            self.inner.get_ref().get_ref().peer_addr()
+        }
+        /// Returns the local address of the underlying TcpStream.
+        pub fn local_addr(&self) -> io::Result<SocketAddr> {
+            self.inner.get_ref().get_ref().local_addr()
+        }
+    }
+
+    /// Returns a new JSON transport that reads from and writes to `io`.
+    pub fn new<Item, SinkItem, Codec>(
+        io: TcpStream,
+        codec: Codec,
+    ) -> Transport<TcpStream, Item, SinkItem, Codec>
+    where
+        Item: for<'de> Deserialize<'de>,
+        SinkItem: Serialize,
+        Codec: Serializer<SinkItem> + Deserializer<Item>,
+    {
+        new_with(io, codec, &Builder::new())
+    }
+
+    /// Like [`new`], but uses `builder` to configure framing and buffering instead of the
+    /// defaults.
+    pub fn new_with<Item, SinkItem, Codec>(
+        io: TcpStream,
+        codec: Codec,
+        builder: &Builder,
+    ) -> Transport<TcpStream, Item, SinkItem, Codec>
+    where
+        Item: for<'de> Deserialize<'de>,
+        SinkItem: Serialize,
+        Codec: Serializer<SinkItem> + Deserializer<Item>,
+    {
+        builder.build(io, codec)
+    }
+
+    /// Connects to `addr`, wrapping the connection in a JSON transport.
+    pub async fn connect<A, Item, SinkItem, Codec>(
+        addr: A,
+        codec: Codec,
+    ) -> io::Result<Transport<TcpStream, Item, SinkItem, Codec>>
+    where
+        A: ToSocketAddrs,
+        Item: for<'de> Deserialize<'de>,
+        SinkItem: Serialize,
+        Codec: Serializer<SinkItem> + Deserializer<Item>,
+    {
+        connect_with(addr, codec, &Builder::new()).await
+    }
+
+    /// Like [`connect`], but uses `builder` to configure framing and buffering instead of the
+    /// defaults.
+    pub async fn connect_with<A, Item, SinkItem, Codec>(
+        addr: A,
+        codec: Codec,
+        builder: &Builder,
+    ) -> io::Result<Transport<TcpStream, Item, SinkItem, Codec>>
+    where
+        A: ToSocketAddrs,
+        Item: for<'de> Deserialize<'de>,
+        SinkItem: Serialize,
+        Codec: Serializer<SinkItem> + Deserializer<Item>,
+    {
+        Ok(new_with(TcpStream::connect(addr).await?, codec, builder))
+    }
+
+    /// Listens on `addr`, wrapping accepted connections in JSON transports.
+    pub async fn listen<A, Item, SinkItem, Codec, CodecFn>(
+        addr: A,
+        codec_fn: CodecFn,
+    ) -> io::Result<Incoming<Item, SinkItem, Codec, CodecFn>>
+    where
+        A: ToSocketAddrs,
+        Item: for<'de> Deserialize<'de>,
+        Codec: Serializer<SinkItem> + Deserializer<Item>,
+        CodecFn: Fn() -> Codec,
+    {
+        listen_with(addr, codec_fn, Builder::new()).await
+    }
+
+    /// Like [`listen`], but uses `builder` to configure framing and buffering, e.g. to reject
+    /// oversized frames before they're deserialized.
+    pub async fn listen_with<A, Item, SinkItem, Codec, CodecFn>(
+        addr: A,
+        codec_fn: CodecFn,
+        builder: Builder,
+    ) -> io::Result<Incoming<Item, SinkItem, Codec, CodecFn>>
+    where
+        A: ToSocketAddrs,
+        Item: for<'de> Deserialize<'de>,
+        Codec: Serializer<SinkItem> + Deserializer<Item>,
+        CodecFn: Fn() -> Codec,
+    {
+        let listener = TcpListener::bind(addr).await?;
+        let local_addr = listener.local_addr()?;
+        Ok(Incoming {
+            listener,
+            codec_fn,
+            local_addr,
+            builder,
+            ghost: PhantomData,
+        })
+    }
+
+    /// A [`TcpListener`] that wraps connections in [transports](Transport).
+    #[pin_project]
+    #[derive(Debug)]
+    pub struct Incoming<Item, SinkItem, Codec, CodecFn> {
+        listener: TcpListener,
+        local_addr: SocketAddr,
+        codec_fn: CodecFn,
+        builder: Builder,
+        ghost: PhantomData<(Item, SinkItem, Codec)>,
+    }
+
+    impl<Item, SinkItem, Codec, CodecFn> Incoming<Item, SinkItem, Codec, CodecFn> {
+        /// Returns the address being listened on.
+        pub fn local_addr(&self) -> SocketAddr {
+            self.local_addr
+        }
+    }
+
+    impl<Item, SinkItem, Codec, CodecFn> Stream for Incoming<Item, SinkItem, Codec, CodecFn>
+    where
+        Item: for<'de> Deserialize<'de>,
+        SinkItem: Serialize,
+        Codec: Serializer<SinkItem> + Deserializer<Item>,
+        CodecFn: Fn() -> Codec,
+    {
+        type Item = io::Result<Transport<TcpStream, Item, SinkItem, Codec>>;
+
+        fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+            let next =
+                ready!(Pin::new(&mut self.as_mut().project().listener.incoming()).poll_next(cx)?);
+            Poll::Ready(
+                next.map(|conn| Ok(new_with(conn, (self.codec_fn)(), &self.builder))),
+            )
+        }
+    }
+}
+
+#[cfg(feature = "proxy-protocol")]
+#[cfg_attr(docsrs, doc(cfg(feature = "proxy-protocol")))]
+/// Support for decoding a [PROXY protocol](https://www.haproxy.org/download/2.3/doc/proxy-protocol.txt)
+/// (v1 and v2) header prepended to a connection by an L4 load balancer, so that the original
+/// client address can be recovered instead of the balancer's.
+pub mod proxy_protocol {
+    use {
+        super::*,
+        futures::stream::StreamExt,
+        std::{
+            marker::PhantomData,
+            net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr},
+        },
+        tokio::{
+            io::AsyncReadExt,
+            net::{TcpListener, TcpStream, ToSocketAddrs},
+        },
+    };
+
+    const V1_PREFIX: &[u8] = b"PROXY ";
+    const V1_MAX_LEN: usize = 107;
+    const V2_SIGNATURE: [u8; 12] = [
+        0x0D, 0x0A, 0x0D, 0x0A, 0x00, 0x0D, 0x0A, 0x51, 0x55, 0x49, 0x54, 0x0A,
+    ];
+
+    /// The original client and destination addresses recovered from a PROXY protocol header.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub struct ProxyAddresses {
+        /// The original client (source) address.
+        pub source: SocketAddr,
+        /// The destination address the client originally connected to, typically the load
+        /// balancer's listening address.
+        pub destination: SocketAddr,
+    }
+
+    /// A stream with a leading PROXY protocol header consumed, exposing the original client
+    /// address the header described (if any).
+    #[pin_project]
+    pub struct ProxiedStream<S> {
+        #[pin]
+        inner: S,
+        proxied_addresses: Option<ProxyAddresses>,
+    }
+
+    impl<S> ProxiedStream<S> {
+        /// Returns the original client and destination addresses recovered from the PROXY
+        /// protocol header, or `None` if the header carried no address: a v1 `PROXY UNKNOWN`
+        /// line, a v2 `LOCAL` command, or a v2 address block that isn't a complete IPv4 or IPv6
+        /// block.
+        pub fn proxied_addresses(&self) -> Option<ProxyAddresses> {
+            self.proxied_addresses
+        }
+    }
+
+    impl<S> ProxiedStream<S>
+    where
+        S: AsyncRead + Unpin,
+    {
+        /// Reads and validates a PROXY protocol header (v1 or v2) from the front of `io`. The
+        /// returned stream continues reading application data immediately following the header.
+        ///
+        /// The first 12 bytes are always read in full via `read_exact`, which loops internally
+        /// over short reads, so a header split across several TCP segments is still recognized
+        /// rather than rejected as malformed.
+        pub async fn new(mut io: S) -> io::Result<Self> {
+            let mut probe = [0u8; 12];
+            io.read_exact(&mut probe).await?;
+            let proxied_addresses = if probe == V2_SIGNATURE {
+                read_v2(&mut io).await?
+            } else if probe.starts_with(V1_PREFIX) {
+                read_v1(&mut io, probe.to_vec()).await?
+            } else {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "missing or malformed PROXY protocol header",
+                ));
+            };
+            Ok(ProxiedStream {
+                inner: io,
+                proxied_addresses,
+            })
+        }
+    }
+
+    /// Parses a v1 header given `line`, the bytes of it already read off `io` (at least the
+    /// `PROXY ` prefix), reading further bytes one at a time until the terminating `\r\n`.
+    async fn read_v1<S>(io: &mut S, mut line: Vec<u8>) -> io::Result<Option<ProxyAddresses>>
+    where
+        S: AsyncRead + Unpin,
+    {
+        let mut byte = [0u8; 1];
+        while !line.ends_with(b"\n") {
+            if line.len() >= V1_MAX_LEN {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "PROXY v1 header exceeds the 107-byte maximum",
+                ));
+            }
+            io.read_exact(&mut byte).await?;
+            line.push(byte[0]);
+        }
+        let line = std::str::from_utf8(&line)
+            .map_err(|_| io::Error::new(io::ErrorKind::InvalidData, "malformed PROXY v1 header"))?
+            .trim_end_matches("\r\n");
+        let mut fields = line.split(' ');
+        match fields.next() {
+            Some("PROXY") => {}
+            _ => {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "malformed PROXY v1 header",
+                ))
+            }
+        }
+        match fields.next() {
+            Some("UNKNOWN") => Ok(None),
+            Some("TCP4") | Some("TCP6") => {
+                let mut parse = || -> Option<ProxyAddresses> {
+                    let src_ip: IpAddr = fields.next()?.parse().ok()?;
+                    let dst_ip: IpAddr = fields.next()?.parse().ok()?;
+                    let src_port: u16 = fields.next()?.parse().ok()?;
+                    let dst_port: u16 = fields.next()?.parse().ok()?;
+                    Some(ProxyAddresses {
+                        source: SocketAddr::new(src_ip, src_port),
+                        destination: SocketAddr::new(dst_ip, dst_port),
+                    })
+                };
+                parse()
+                    .map(Some)
+                    .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "malformed PROXY v1 header"))
+            }
+            _ => Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "malformed PROXY v1 header",
+            )),
+        }
+    }
+
+    /// Parses a v2 header from `io`, whose first 12 signature bytes have already been consumed.
+    async fn read_v2<S>(io: &mut S) -> io::Result<Option<ProxyAddresses>>
+    where
+        S: AsyncRead + Unpin,
+    {
+        let mut header = [0u8; 4];
+        io.read_exact(&mut header).await?;
+        let version_command = header[0];
+        if version_command >> 4 != 0x2 {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "unsupported PROXY protocol version",
+            ));
+        }
+        let command = version_command & 0x0F;
+        let family_transport = header[1];
+        let family = family_transport >> 4;
+        let len = u16::from_be_bytes([header[2], header[3]]) as usize;
+
+        let mut addr_block = vec![0u8; len];
+        io.read_exact(&mut addr_block).await?;
+
+        // A LOCAL command (e.g. a health check) carries no meaningful address.
+        if command == 0x0 {
+            return Ok(None);
+        }
+
+        match family {
+            // AF_INET
+            0x1 if addr_block.len() >= 12 => {
+                let src_ip = Ipv4Addr::new(addr_block[0], addr_block[1], addr_block[2], addr_block[3]);
+                let dst_ip = Ipv4Addr::new(addr_block[4], addr_block[5], addr_block[6], addr_block[7]);
+                let src_port = u16::from_be_bytes([addr_block[8], addr_block[9]]);
+                let dst_port = u16::from_be_bytes([addr_block[10], addr_block[11]]);
+                Ok(Some(ProxyAddresses {
+                    source: SocketAddr::new(IpAddr::V4(src_ip), src_port),
+                    destination: SocketAddr::new(IpAddr::V4(dst_ip), dst_port),
+                }))
+            }
+            // AF_INET6
+            0x2 if addr_block.len() >= 36 => {
+                let mut src = [0u8; 16];
+                let mut dst = [0u8; 16];
+                src.copy_from_slice(&addr_block[0..16]);
+                dst.copy_from_slice(&addr_block[16..32]);
+                let src_port = u16::from_be_bytes([addr_block[32], addr_block[33]]);
+                let dst_port = u16::from_be_bytes([addr_block[34], addr_block[35]]);
+                Ok(Some(ProxyAddresses {
+                    source: SocketAddr::new(IpAddr::V6(Ipv6Addr::from(src)), src_port),
+                    destination: SocketAddr::new(IpAddr::V6(Ipv6Addr::from(dst)), dst_port),
+                }))
+            }
+            // AF_UNSPEC, an unsupported family, or an IPv4/IPv6 address block too short to hold
+            // both addresses and ports.
+            _ => Ok(None),
+        }
+    }
+
+    impl<S> AsyncRead for ProxiedStream<S>
+    where
+        S: AsyncRead,
+    {
+        fn poll_read(
+            self: Pin<&mut Self>,
+            cx: &mut Context<'_>,
+            buf: &mut [u8],
+        ) -> Poll<io::Result<usize>> {
+            self.project().inner.poll_read(cx, buf)
+        }
+    }
+
+    impl<S> AsyncWrite for ProxiedStream<S>
+    where
+        S: AsyncWrite,
+    {
+        fn poll_write(
+            self: Pin<&mut Self>,
+            cx: &mut Context<'_>,
+            buf: &[u8],
+        ) -> Poll<io::Result<usize>> {
+            self.project().inner.poll_write(cx, buf)
+        }
+
+        fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+            self.project().inner.poll_flush(cx)
+        }
+
+        fn poll_shutdown(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+            self.project().inner.poll_shutdown(cx)
+        }
+    }
+
+    impl<Item, SinkItem, Codec> Transport<ProxiedStream<TcpStream>, Item, SinkItem, Codec> {
+        /// Returns the original client and destination addresses recovered from the PROXY
+        /// protocol header, or `None` if the header carried no address (see
+        /// [`ProxiedStream::proxied_addresses`]).
+        pub fn proxied_peer_addr(&self) -> Option<ProxyAddresses> {
+            self.inner.get_ref().get_ref().proxied_addresses()
+        }
+    }
+
+    /// Reads a PROXY protocol header off an already-accepted `conn` (e.g. from
+    /// [`TcpListener::accept`](tokio::net::TcpListener::accept)), then wraps the remaining
+    /// connection in a transport. Use this only on connections originating from a PROXY-protocol-aware load
+    /// balancer — a direct client connection will fail the handshake.
+    pub async fn accept<Item, SinkItem, Codec>(
+        conn: TcpStream,
+        codec: Codec,
+    ) -> io::Result<Transport<ProxiedStream<TcpStream>, Item, SinkItem, Codec>>
+    where
+        Item: for<'de> Deserialize<'de>,
+        SinkItem: Serialize,
+        Codec: Serializer<SinkItem> + Deserializer<Item>,
+    {
+        let proxied = ProxiedStream::new(conn).await?;
+        Ok(Transport::from((proxied, codec)))
+    }
+
+    /// Listens on `addr` like [`tcp::listen`](super::tcp::listen), but first reads a PROXY
+    /// protocol header off each accepted connection before wrapping it in a transport. Use this
+    /// only behind a PROXY-protocol-aware load balancer — a direct client connection will fail
+    /// the handshake, and the stream yields that failure as an `Err` item.
+    pub async fn listen<A, Item, SinkItem, Codec, CodecFn>(
+        addr: A,
+        codec_fn: CodecFn,
+    ) -> io::Result<Incoming<Item, SinkItem, Codec, CodecFn>>
+    where
+        A: ToSocketAddrs,
+        Item: for<'de> Deserialize<'de> + Send + 'static,
+        SinkItem: Serialize + Send + 'static,
+        Codec: Serializer<SinkItem> + Deserializer<Item> + Send + 'static,
+        CodecFn: Fn() -> Codec + Send + 'static,
+    {
+        let listener = TcpListener::bind(addr).await?;
+        let local_addr = listener.local_addr()?;
+        let handshakes = listener.incoming().then(move |conn| {
+            let codec = codec_fn();
+            async move { accept(conn?, codec).await }
+        });
+        Ok(Incoming {
+            handshakes: Box::pin(handshakes),
+            local_addr,
+            ghost: PhantomData,
+        })
+    }
+
+    /// A [`TcpListener`](tokio::net::TcpListener) that reads a PROXY protocol header off each
+    /// accepted connection and wraps it in a [transport](Transport).
+    pub struct Incoming<Item, SinkItem, Codec, CodecFn> {
+        handshakes: Pin<
+            Box<
+                dyn Stream<Item = io::Result<Transport<ProxiedStream<TcpStream>, Item, SinkItem, Codec>>>
+                    + Send,
+            >,
+        >,
+        local_addr: SocketAddr,
+        // `CodecFn` is only used to produce `handshakes` before it's erased into the boxed
+        // stream above; this keeps `listen`'s return type spelled the same as the other
+        // transports' `Incoming` types.
+        ghost: PhantomData<CodecFn>,
+    }
+
+    impl<Item, SinkItem, Codec, CodecFn> Incoming<Item, SinkItem, Codec, CodecFn> {
+        /// Returns the address being listened on.
+        pub fn local_addr(&self) -> SocketAddr {
+            self.local_addr
+        }
+    }
+
+    impl<Item, SinkItem, Codec, CodecFn> Stream for Incoming<Item, SinkItem, Codec, CodecFn> {
+        type Item = io::Result<Transport<ProxiedStream<TcpStream>, Item, SinkItem, Codec>>;
+
+        fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+            self.handshakes.as_mut().poll_next(cx)
+        }
+    }
+}
+
+#[cfg(feature = "tls")]
+#[cfg_attr(docsrs, doc(cfg(feature = "tls")))]
+/// TLS support for generic transport using Tokio, backed by either `rustls` (feature `tls-rustls`)
+/// or `native-tls` (feature `tls-native-tls`). Enable exactly one backend.
+pub mod tls {
+    #[cfg(feature = "tls-rustls")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "tls-rustls")))]
+    pub mod rustls {
+        use {
+            super::super::tcp::*,
+            crate::serde_transport::*,
+            futures::stream::StreamExt,
+            std::{net::SocketAddr, sync::Arc},
+            tokio::net::{TcpListener, TcpStream, ToSocketAddrs},
+            tokio_rustls::{
+                client::TlsStream as ClientTlsStream,
+                rustls::{pki_types::ServerName, ClientConfig},
+                server::TlsStream as ServerTlsStream,
+                TlsAcceptor, TlsConnector,
+            },
+        };
+
+        /// Connects to `addr`, performs a TLS handshake as `domain`, and wraps the resulting
+        /// stream in a transport.
+        pub async fn connect<A, Item, SinkItem, Codec>(
+            addr: A,
+            domain: &str,
+            config: Arc<ClientConfig>,
+            codec: Codec,
+        ) -> io::Result<Transport<ClientTlsStream<TcpStream>, Item, SinkItem, Codec>>
+        where
+            A: ToSocketAddrs,
+            Item: for<'de> Deserialize<'de>,
+            SinkItem: Serialize,
+            Codec: Serializer<SinkItem> + Deserializer<Item>,
+        {
+            let tcp = TcpStream::connect(addr).await?;
+            let domain = ServerName::try_from(domain.to_string()).map_err(|_| {
+                io::Error::new(io::ErrorKind::InvalidInput, "invalid DNS domain name")
+            })?;
+            let tls = TlsConnector::from(config).connect(domain, tcp).await?;
+            Ok(Transport::from((tls, codec)))
+        }
+
+        /// Performs a server-side TLS handshake over an already-accepted `TcpStream`, then wraps
+        /// it in a transport.
+        pub async fn accept<Item, SinkItem, Codec>(
+            conn: TcpStream,
+            acceptor: &TlsAcceptor,
+            codec: Codec,
+        ) -> io::Result<Transport<ServerTlsStream<TcpStream>, Item, SinkItem, Codec>>
+        where
+            Item: for<'de> Deserialize<'de>,
+            SinkItem: Serialize,
+            Codec: Serializer<SinkItem> + Deserializer<Item>,
+        {
+            let tls = acceptor.accept(conn).await?;
+            Ok(Transport::from((tls, codec)))
+        }
+
+        /// Listens on `addr`, performing a TLS handshake on each accepted connection before
+        /// wrapping it in a transport.
+        pub async fn listen<A, Item, SinkItem, Codec, CodecFn>(
+            addr: A,
+            acceptor: TlsAcceptor,
+            codec_fn: CodecFn,
+        ) -> io::Result<Incoming<Item, SinkItem, Codec, CodecFn>>
+        where
+            A: ToSocketAddrs,
+            Item: for<'de> Deserialize<'de> + Send + 'static,
+            SinkItem: Serialize + Send + 'static,
+            Codec: Serializer<SinkItem> + Deserializer<Item> + Send + 'static,
+            CodecFn: Fn() -> Codec + Send + 'static,
+        {
+            let listener = TcpListener::bind(addr).await?;
+            let local_addr = listener.local_addr()?;
+            let handshakes = listener.incoming().then(move |conn| {
+                let acceptor = acceptor.clone();
+                let codec = codec_fn();
+                async move { accept(conn?, &acceptor, codec).await }
+            });
+            Ok(Incoming {
+                handshakes: Box::pin(handshakes),
+                local_addr,
+                ghost: std::marker::PhantomData,
+            })
+        }
+
+        /// A `TcpListener` that performs a TLS handshake on each accepted connection and wraps it
+        /// in a [transport](Transport).
+        pub struct Incoming<Item, SinkItem, Codec, CodecFn> {
+            handshakes: Pin<
+                Box<
+                    dyn Stream<Item = io::Result<Transport<ServerTlsStream<TcpStream>, Item, SinkItem, Codec>>>
+                        + Send,
+                >,
+            >,
+            local_addr: SocketAddr,
+            // `CodecFn` is only used to produce `handshakes` before it's erased into the boxed
+            // stream above; this keeps `listen`'s return type spelled the same as the other
+            // transports' `Incoming` types.
+            ghost: std::marker::PhantomData<CodecFn>,
+        }
+
+        impl<Item, SinkItem, Codec, CodecFn> Incoming<Item, SinkItem, Codec, CodecFn> {
+            /// Returns the address being listened on.
+            pub fn local_addr(&self) -> SocketAddr {
+                self.local_addr
+            }
+        }
+
+        impl<Item, SinkItem, Codec, CodecFn> Stream for Incoming<Item, SinkItem, Codec, CodecFn> {
+            type Item = io::Result<Transport<ServerTlsStream<TcpStream>, Item, SinkItem, Codec>>;
+
+            fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+                self.handshakes.as_mut().poll_next(cx)
+            }
+        }
+    }
+
+    #[cfg(feature = "tls-native-tls")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "tls-native-tls")))]
+    pub mod native_tls {
+        use {
+            super::super::tcp::*,
+            crate::serde_transport::*,
+            futures::stream::StreamExt,
+            std::net::SocketAddr,
+            tokio::net::{TcpListener, TcpStream, ToSocketAddrs},
+            tokio_native_tls::{native_tls::TlsConnector as NativeTlsConnector, TlsAcceptor, TlsConnector, TlsStream},
+        };
+
+        /// Connects to `addr`, performs a TLS handshake as `domain`, and wraps the resulting
+        /// stream in a transport.
+        pub async fn connect<A, Item, SinkItem, Codec>(
+            addr: A,
+            domain: &str,
+            connector: NativeTlsConnector,
+            codec: Codec,
+        ) -> io::Result<Transport<TlsStream<TcpStream>, Item, SinkItem, Codec>>
+        where
+            A: ToSocketAddrs,
+            Item: for<'de> Deserialize<'de>,
+            SinkItem: Serialize,
+            Codec: Serializer<SinkItem> + Deserializer<Item>,
+        {
+            let tcp = TcpStream::connect(addr).await?;
+            let tls = TlsConnector::from(connector)
+                .connect(domain, tcp)
+                .await
+                .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?;
+            Ok(Transport::from((tls, codec)))
+        }
+
+        /// Performs a server-side TLS handshake over an already-accepted `TcpStream`, then wraps
+        /// it in a transport.
+        pub async fn accept<Item, SinkItem, Codec>(
+            conn: TcpStream,
+            acceptor: &TlsAcceptor,
+            codec: Codec,
+        ) -> io::Result<Transport<TlsStream<TcpStream>, Item, SinkItem, Codec>>
+        where
+            Item: for<'de> Deserialize<'de>,
+            SinkItem: Serialize,
+            Codec: Serializer<SinkItem> + Deserializer<Item>,
+        {
+            let tls = acceptor
+                .accept(conn)
+                .await
+                .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?;
+            Ok(Transport::from((tls, codec)))
+        }
+
+        /// Listens on `addr`, performing a TLS handshake on each accepted connection before
+        /// wrapping it in a transport.
+        pub async fn listen<A, Item, SinkItem, Codec, CodecFn>(
+            addr: A,
+            acceptor: TlsAcceptor,
+            codec_fn: CodecFn,
+        ) -> io::Result<Incoming<Item, SinkItem, Codec, CodecFn>>
+        where
+            A: ToSocketAddrs,
+            Item: for<'de> Deserialize<'de> + Send + 'static,
+            SinkItem: Serialize + Send + 'static,
+            Codec: Serializer<SinkItem> + Deserializer<Item> + Send + 'static,
+            CodecFn: Fn() -> Codec + Send + 'static,
+        {
+            let listener = TcpListener::bind(addr).await?;
+            let local_addr = listener.local_addr()?;
+            let handshakes = listener.incoming().then(move |conn| {
+                let acceptor = acceptor.clone();
+                let codec = codec_fn();
+                async move { accept(conn?, &acceptor, codec).await }
+            });
+            Ok(Incoming {
+                handshakes: Box::pin(handshakes),
+                local_addr,
+                ghost: std::marker::PhantomData,
+            })
+        }
+
+        /// A `TcpListener` that performs a TLS handshake on each accepted connection and wraps it
+        /// in a [transport](Transport).
+        pub struct Incoming<Item, SinkItem, Codec, CodecFn> {
+            handshakes: Pin<
+                Box<dyn Stream<Item = io::Result<Transport<TlsStream<TcpStream>, Item, SinkItem, Codec>>> + Send>,
+            >,
+            local_addr: SocketAddr,
+            // `CodecFn` is only used to produce `handshakes` before it's erased into the boxed
+            // stream above; this keeps `listen`'s return type spelled the same as the other
+            // transports' `Incoming` types.
+            ghost: std::marker::PhantomData<CodecFn>,
+        }
+
+        impl<Item, SinkItem, Codec, CodecFn> Incoming<Item, SinkItem, Codec, CodecFn> {
+            /// Returns the address being listened on.
+            pub fn local_addr(&self) -> SocketAddr {
+                self.local_addr
+            }
+        }
+
+        impl<Item, SinkItem, Codec, CodecFn> Stream for Incoming<Item, SinkItem, Codec, CodecFn> {
+            type Item = io::Result<Transport<TlsStream<TcpStream>, Item, SinkItem, Codec>>;
+
+            fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+                self.handshakes.as_mut().poll_next(cx)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "ws")]
+#[cfg_attr(docsrs, doc(cfg(feature = "ws")))]
+/// WebSocket support for generic transport, so tarpc services can be reached through HTTP
+/// proxies and from WASM/browser clients. Each WebSocket binary message carries exactly one
+/// serialized item, in place of [`Transport`]'s length-delimited framing.
+pub mod ws {
+    use {
+        super::*,
+        futures::ready,
+        std::marker::PhantomData,
+        tokio::net::{TcpListener, TcpStream, ToSocketAddrs},
+        tokio_tungstenite::{
+            tungstenite::{Error as WsError, Message},
+            MaybeTlsStream, WebSocketStream,
+        },
+    };
+
+    fn ws_err_to_io(e: WsError) -> io::Error {
+        match e {
+            WsError::Io(e) => e,
+            e => io::Error::new(io::ErrorKind::Other, e),
+        }
+    }
+
+    /// A transport that serializes each item to, and deserializes each item from, a single
+    /// WebSocket binary message.
+    #[pin_project]
+    pub struct Transport<S, Item, SinkItem, Codec> {
+        #[pin]
+        inner: WebSocketStream<S>,
+        codec: Codec,
+        ghost: PhantomData<(Item, SinkItem)>,
+    }
+
+    /// Wraps an already-established WebSocket connection in a transport.
+    pub fn new<S, Item, SinkItem, Codec>(
+        ws: WebSocketStream<S>,
+        codec: Codec,
+    ) -> Transport<S, Item, SinkItem, Codec>
+    where
+        Item: for<'de> Deserialize<'de>,
+        SinkItem: Serialize,
+        Codec: Serializer<SinkItem> + Deserializer<Item>,
+    {
+        Transport {
+            inner: ws,
+            codec,
+            ghost: PhantomData,
+        }
+    }
+
+    /// Connects to `url`, performing the WebSocket handshake, and wraps the connection in a
+    /// transport.
+    pub async fn connect<Item, SinkItem, Codec>(
+        url: &str,
+        codec: Codec,
+    ) -> io::Result<Transport<MaybeTlsStream<TcpStream>, Item, SinkItem, Codec>>
+    where
+        Item: for<'de> Deserialize<'de>,
+        SinkItem: Serialize,
+        Codec: Serializer<SinkItem> + Deserializer<Item>,
+    {
+        let (ws, _response) = tokio_tungstenite::connect_async(url)
+            .await
+            .map_err(ws_err_to_io)?;
+        Ok(new(ws, codec))
+    }
 
-impl<S, Item, SinkItem, Codec, CodecError> Sink<SinkItem> for Transport<S, Item, SinkItem, Codec>
-where
-    S: AsyncWrite,
-    SinkItem: Serialize,
-    Codec: Serializer<SinkItem>,
-    CodecError: Into<Box<dyn Error + Send + Sync>>,
-    SerdeFramed<Framed<S, LengthDelimitedCodec>, Item, SinkItem, Codec>:
-        Sink<SinkItem, Error = CodecError>,
-{
-    type Error = io::Error;
+    /// Listens on `addr`, upgrading incoming HTTP connections to WebSocket and wrapping each in a
+    /// transport.
+    pub async fn listen<A, Item, SinkItem, Codec, CodecFn>(
+        addr: A,
+        codec_fn: CodecFn,
+    ) -> io::Result<Incoming<Item, SinkItem, Codec, CodecFn>>
+    where
+        A: ToSocketAddrs,
+        Item: for<'de> Deserialize<'de> + Send + 'static,
+        SinkItem: Serialize + Send + 'static,
+        Codec: Serializer<SinkItem> + Deserializer<Item> + Send + 'static,
+        CodecFn: Fn() -> Codec + Send + 'static,
+    {
+        let listener = TcpListener::bind(addr).await?;
+        let local_addr = listener.local_addr()?;
+        let upgrades = listener.incoming().then(move |conn| {
+            let codec = codec_fn();
+            async move {
+                let conn = conn?;
+                let ws = tokio_tungstenite::accept_async(conn)
+                    .await
+                    .map_err(ws_err_to_io)?;
+                Ok(new(ws, codec))
+            }
+        });
+        Ok(Incoming {
+            upgrades: Box::pin(upgrades),
+            local_addr,
+            ghost: PhantomData,
+        })
+    }
 
-    fn poll_ready(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
-        convert(self.project().inner.poll_ready(cx))
+    /// A [`TcpListener`] that upgrades connections to WebSocket and wraps them in
+    /// [transports](Transport).
+    pub struct Incoming<Item, SinkItem, Codec, CodecFn> {
+        upgrades: Pin<
+            Box<dyn Stream<Item = io::Result<Transport<TcpStream, Item, SinkItem, Codec>>> + Send>,
+        >,
+        local_addr: std::net::SocketAddr,
+        // `CodecFn` is only used to produce `upgrades` before it's erased into the boxed stream
+        // above; this keeps `listen`'s return type spelled the same as the other transports'
+        // `Incoming` types.
+        ghost: PhantomData<CodecFn>,
     }
 
-    fn start_send(self: Pin<&mut Self>, item: SinkItem) -> io::Result<()> {
-        self.project()
-            .inner
-            .start_send(item)
-            .map_err(|e| io::Error::new(io::ErrorKind::Other, e))
+    impl<Item, SinkItem, Codec, CodecFn> Incoming<Item, SinkItem, Codec, CodecFn> {
+        /// Returns the address being listened on.
+        pub fn local_addr(&self) -> std::net::SocketAddr {
+            self.local_addr
+        }
     }
 
-    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
-        convert(self.project().inner.poll_flush(cx))
+    impl<Item, SinkItem, Codec, CodecFn> Stream for Incoming<Item, SinkItem, Codec, CodecFn> {
+        type Item = io::Result<Transport<TcpStream, Item, SinkItem, Codec>>;
+
+        fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+            self.upgrades.as_mut().poll_next(cx)
+        }
     }
 
-    fn poll_close(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
-        convert(self.project().inner.poll_close(cx))
+    impl<S, Item, SinkItem, Codec, CodecError> Stream for Transport<S, Item, SinkItem, Codec>
+    where
+        S: tokio::io::AsyncRead + tokio::io::AsyncWrite + Unpin,
+        Item: for<'de> Deserialize<'de> + Unpin,
+        Codec: Deserializer<Item, Error = CodecError> + Unpin,
+        CodecError: Into<Box<dyn Error + Send + Sync>>,
+    {
+        type Item = io::Result<Item>;
+
+        fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<io::Result<Item>>> {
+            let mut this = self.project();
+            loop {
+                match ready!(this.inner.as_mut().poll_next(cx)) {
+                    None => return Poll::Ready(None),
+                    Some(Err(e)) => return Poll::Ready(Some(Err(ws_err_to_io(e)))),
+                    Some(Ok(Message::Binary(bytes))) => {
+                        let item = Pin::new(&mut *this.codec)
+                            .deserialize(&bytes::BytesMut::from(&bytes[..]))
+                            .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?;
+                        return Poll::Ready(Some(Ok(item)));
+                    }
+                    // Control frames (ping/pong/close) and text frames carry no item; keep
+                    // polling for the next binary message.
+                    Some(Ok(_)) => continue,
+                }
+            }
+        }
     }
-}
 
-fn convert<E: Into<Box<dyn Error + Send + Sync>>>(
-    poll: Poll<Result<(), E>>,
-) -> Poll<io::Result<()>> {
-    poll.map(|ready| ready.map_err(|e| io::Error::new(io::ErrorKind::Other, e)))
-}
+    impl<S, Item, SinkItem, Codec, CodecError> Sink<SinkItem> for Transport<S, Item, SinkItem, Codec>
+    where
+        S: tokio::io::AsyncRead + tokio::io::AsyncWrite + Unpin,
+        SinkItem: Serialize + Unpin,
+        Codec: Serializer<SinkItem, Error = CodecError> + Unpin,
+        CodecError: Into<Box<dyn Error + Send + Sync>>,
+    {
+        type Error = io::Error;
 
-impl<S, Item, SinkItem, Codec> From<(S, Codec)> for Transport<S, Item, SinkItem, Codec>
-where
-    S: AsyncWrite + AsyncRead,
-    Item: for<'de> Deserialize<'de>,
-    SinkItem: Serialize,
-    Codec: Serializer<SinkItem> + Deserializer<Item>,
-{
-    fn from((inner, codec): (S, Codec)) -> Self {
-        Transport {
-            inner: SerdeFramed::new(Framed::new(inner, LengthDelimitedCodec::new()), codec),
+        fn poll_ready(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+            self.project().inner.poll_ready(cx).map_err(ws_err_to_io)
+        }
+
+        fn start_send(self: Pin<&mut Self>, item: SinkItem) -> io::Result<()> {
+            let mut this = self.project();
+            let bytes = Pin::new(&mut *this.codec)
+                .serialize(&item)
+                .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?;
+            this.inner
+                .start_send(Message::Binary(bytes.to_vec()))
+                .map_err(ws_err_to_io)
+        }
+
+        fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+            self.project().inner.poll_flush(cx).map_err(ws_err_to_io)
+        }
+
+        fn poll_close(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+            self.project().inner.poll_close(cx).map_err(ws_err_to_io)
         }
     }
 }
 
-#[cfg(feature = "tcp")]
-#[cfg_attr(docsrs, doc(cfg(feature = "tcp")))]
-/// TCP support for generic transport using Tokio.
-pub mod tcp {
+#[cfg(feature = "unix")]
+#[cfg_attr(docsrs, doc(cfg(feature = "unix")))]
+/// Unix domain socket support for generic transport using Tokio.
+pub mod unix {
     use {
         super::*,
         futures::ready,
-        std::{marker::PhantomData, net::SocketAddr},
-        tokio::net::{TcpListener, TcpStream, ToSocketAddrs},
+        std::{marker::PhantomData, os::unix::net::SocketAddr, path::Path},
+        tokio::net::{UnixListener, UnixStream},
     };
 
     mod private {
@@ -120,15 +1139,15 @@ pub mod tcp {
 
         pub trait Sealed {}
 
-        impl<Item, SinkItem, Codec> Sealed for Transport<TcpStream, Item, SinkItem, Codec> {}
+        impl<Item, SinkItem, Codec> Sealed for Transport<UnixStream, Item, SinkItem, Codec> {}
     }
 
-    impl<Item, SinkItem, Codec> Transport<TcpStream, Item, SinkItem, Codec> {
-        /// Returns the peer address of the underlying TcpStream.
+    impl<Item, SinkItem, Codec> Transport<UnixStream, Item, SinkItem, Codec> {
+        /// Returns the peer address of the underlying UnixStream.
         pub fn peer_addr(&self) -> io::Result<SocketAddr> {
             self.inner.get_ref().get_ref().peer_addr()
         }
-        /// Returns the local address of the underlying TcpStream.
+        /// Returns the local address of the underlying UnixStream.
         pub fn local_addr(&self) -> io::Result<SocketAddr> {
             self.inner.get_ref().get_ref().local_addr()
         }
@@ -136,9 +1155,9 @@ pub mod tcp {
 
     /// Returns a new JSON transport that reads from and writes to `io`.
     pub fn new<Item, SinkItem, Codec>(
-        io: TcpStream,
+        io: UnixStream,
         codec: Codec,
-    ) -> Transport<TcpStream, Item, SinkItem, Codec>
+    ) -> Transport<UnixStream, Item, SinkItem, Codec>
     where
         Item: for<'de> Deserialize<'de>,
         SinkItem: Serialize,
@@ -147,32 +1166,32 @@ pub mod tcp {
         Transport::from((io, codec))
     }
 
-    /// Connects to `addr`, wrapping the connection in a JSON transport.
-    pub async fn connect<A, Item, SinkItem, Codec>(
-        addr: A,
+    /// Connects to `path`, wrapping the connection in a JSON transport.
+    pub async fn connect<P, Item, SinkItem, Codec>(
+        path: P,
         codec: Codec,
-    ) -> io::Result<Transport<TcpStream, Item, SinkItem, Codec>>
+    ) -> io::Result<Transport<UnixStream, Item, SinkItem, Codec>>
     where
-        A: ToSocketAddrs,
+        P: AsRef<Path>,
         Item: for<'de> Deserialize<'de>,
         SinkItem: Serialize,
         Codec: Serializer<SinkItem> + Deserializer<Item>,
     {
-        Ok(new(TcpStream::connect(addr).await?, codec))
+        Ok(new(UnixStream::connect(path).await?, codec))
     }
 
-    /// Listens on `addr`, wrapping accepted connections in JSON transports.
-    pub async fn listen<A, Item, SinkItem, Codec, CodecFn>(
-        addr: A,
+    /// Listens on `path`, wrapping accepted connections in JSON transports.
+    pub async fn listen<P, Item, SinkItem, Codec, CodecFn>(
+        path: P,
         codec_fn: CodecFn,
     ) -> io::Result<Incoming<Item, SinkItem, Codec, CodecFn>>
     where
-        A: ToSocketAddrs,
+        P: AsRef<Path>,
         Item: for<'de> Deserialize<'de>,
         Codec: Serializer<SinkItem> + Deserializer<Item>,
         CodecFn: Fn() -> Codec,
     {
-        let listener = TcpListener::bind(addr).await?;
+        let listener = UnixListener::bind(path)?;
         let local_addr = listener.local_addr()?;
         Ok(Incoming {
             listener,
@@ -182,11 +1201,11 @@ pub mod tcp {
         })
     }
 
-    /// A [`TcpListener`] that wraps connections in [transports](Transport).
+    /// A [`UnixListener`] that wraps connections in [transports](Transport).
     #[pin_project]
     #[derive(Debug)]
     pub struct Incoming<Item, SinkItem, Codec, CodecFn> {
-        listener: TcpListener,
+        listener: UnixListener,
         local_addr: SocketAddr,
         codec_fn: CodecFn,
         ghost: PhantomData<(Item, SinkItem, Codec)>,
@@ -194,8 +1213,11 @@ pub mod tcp {
 
     impl<Item, SinkItem, Codec, CodecFn> Incoming<Item, SinkItem, Codec, CodecFn> {
         /// Returns the address being listened on.
+        ///
+        /// Unlike [`tcp::Incoming::local_addr`](super::tcp::Incoming::local_addr), this clones
+        /// rather than copies: `std::os::unix::net::SocketAddr` doesn't implement `Copy`.
         pub fn local_addr(&self) -> SocketAddr {
-            self.local_addr
+            self.local_addr.clone()
         }
     }
 
@@ -206,7 +1228,7 @@ pub mod tcp {
         Codec: Serializer<SinkItem> + Deserializer<Item>,
         CodecFn: Fn() -> Codec,
     {
-        type Item = io::Result<Transport<TcpStream, Item, SinkItem, Codec>>;
+        type Item = io::Result<Transport<UnixStream, Item, SinkItem, Codec>>;
 
         fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
             let next =
@@ -216,6 +1238,206 @@ pub mod tcp {
     }
 }
 
+#[cfg(feature = "udp")]
+#[cfg_attr(docsrs, doc(cfg(feature = "udp")))]
+/// UDP support for the generic Serde transport. Unlike [`Transport`], no length-delimited
+/// framing is used, since datagram boundaries are already preserved by the socket. This suits
+/// low-latency, fire-and-forget RPC where reliable, ordered delivery isn't required.
+pub mod udp {
+    use {
+        super::*,
+        std::net::{Ipv4Addr, SocketAddr},
+        tokio::net::{ToSocketAddrs, UdpSocket},
+    };
+
+    /// The largest datagram that will be read off the wire in one receive.
+    const MAX_DATAGRAM_SIZE: usize = 65_507;
+
+    /// A transport that serializes each item to, and deserializes each item from, a single UDP
+    /// datagram.
+    pub struct Transport<Item, SinkItem, Codec> {
+        socket: UdpSocket,
+        codec: Codec,
+        pending: Option<(bytes::Bytes, SocketAddr)>,
+        ghost: std::marker::PhantomData<(Item, SinkItem)>,
+    }
+
+    impl<Item, SinkItem, Codec> Transport<Item, SinkItem, Codec> {
+        /// Returns the local address that this transport is bound to.
+        pub fn local_addr(&self) -> io::Result<SocketAddr> {
+            self.socket.local_addr()
+        }
+    }
+
+    /// Binds to `addr`, wrapping the socket in a datagram transport.
+    pub async fn bind<A, Item, SinkItem, Codec>(
+        addr: A,
+        codec: Codec,
+    ) -> io::Result<Transport<Item, SinkItem, Codec>>
+    where
+        A: ToSocketAddrs,
+    {
+        Ok(Transport {
+            socket: UdpSocket::bind(addr).await?,
+            codec,
+            pending: None,
+            ghost: std::marker::PhantomData,
+        })
+    }
+
+    /// Binds to an ephemeral local address and connects the socket to `addr`, so that
+    /// subsequently sent items don't need a destination and received datagrams are filtered to
+    /// that peer.
+    pub async fn connect<A, Item, SinkItem, Codec>(
+        addr: A,
+        codec: Codec,
+    ) -> io::Result<ConnectedTransport<Item, SinkItem, Codec>>
+    where
+        A: ToSocketAddrs,
+    {
+        let socket = UdpSocket::bind((Ipv4Addr::UNSPECIFIED, 0)).await?;
+        socket.connect(addr).await?;
+        Ok(ConnectedTransport {
+            socket,
+            codec,
+            pending: None,
+            ghost: std::marker::PhantomData,
+        })
+    }
+
+    /// A transport around a [`UdpSocket`] that has been [`connect`](UdpSocket::connect)ed to a
+    /// single peer, so items can be sent and received without naming a [`SocketAddr`] on every
+    /// call.
+    pub struct ConnectedTransport<Item, SinkItem, Codec> {
+        socket: UdpSocket,
+        codec: Codec,
+        pending: Option<bytes::Bytes>,
+        ghost: std::marker::PhantomData<(Item, SinkItem)>,
+    }
+
+    impl<Item, SinkItem, Codec> ConnectedTransport<Item, SinkItem, Codec> {
+        /// Returns the local address that this transport is bound to.
+        pub fn local_addr(&self) -> io::Result<SocketAddr> {
+            self.socket.local_addr()
+        }
+    }
+
+    impl<Item, SinkItem, Codec, CodecError> Stream for ConnectedTransport<Item, SinkItem, Codec>
+    where
+        Item: for<'de> Deserialize<'de> + Unpin,
+        Codec: Deserializer<Item, Error = CodecError> + Unpin,
+        CodecError: Into<Box<dyn Error + Send + Sync>>,
+    {
+        type Item = io::Result<Item>;
+
+        fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+            let this = self.get_mut();
+            let mut buf = [0u8; MAX_DATAGRAM_SIZE];
+            let n = futures::ready!(this.socket.poll_recv(cx, &mut buf))?;
+            let item = Pin::new(&mut this.codec)
+                .deserialize(&bytes::BytesMut::from(&buf[..n]))
+                .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?;
+            Poll::Ready(Some(Ok(item)))
+        }
+    }
+
+    impl<Item, SinkItem, Codec, CodecError> Sink<SinkItem> for ConnectedTransport<Item, SinkItem, Codec>
+    where
+        SinkItem: Serialize + Unpin,
+        Codec: Serializer<SinkItem, Error = CodecError> + Unpin,
+        CodecError: Into<Box<dyn Error + Send + Sync>>,
+    {
+        type Error = io::Error;
+
+        fn poll_ready(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+            self.poll_flush(cx)
+        }
+
+        fn start_send(self: Pin<&mut Self>, item: SinkItem) -> io::Result<()> {
+            let this = self.get_mut();
+            debug_assert!(this.pending.is_none());
+            let bytes = Pin::new(&mut this.codec)
+                .serialize(&item)
+                .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?;
+            this.pending = Some(bytes);
+            Ok(())
+        }
+
+        fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+            let this = self.get_mut();
+            if let Some(bytes) = &this.pending {
+                futures::ready!(this.socket.poll_send(cx, bytes))?;
+                this.pending = None;
+            }
+            Poll::Ready(Ok(()))
+        }
+
+        fn poll_close(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+            self.poll_flush(cx)
+        }
+    }
+
+    impl<Item, SinkItem, Codec, CodecError> Stream for Transport<Item, SinkItem, Codec>
+    where
+        Item: for<'de> Deserialize<'de> + Unpin,
+        Codec: Deserializer<Item, Error = CodecError> + Unpin,
+        CodecError: Into<Box<dyn Error + Send + Sync>>,
+    {
+        /// Each received datagram, paired with the peer address it arrived from.
+        type Item = io::Result<(Item, SocketAddr)>;
+
+        fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+            let this = self.get_mut();
+            let mut buf = [0u8; MAX_DATAGRAM_SIZE];
+            let (n, peer_addr) = futures::ready!(this.socket.poll_recv_from(cx, &mut buf))?;
+            let item = Pin::new(&mut this.codec)
+                .deserialize(&bytes::BytesMut::from(&buf[..n]))
+                .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?;
+            Poll::Ready(Some(Ok((item, peer_addr))))
+        }
+    }
+
+    impl<Item, SinkItem, Codec, CodecError> Sink<(SinkItem, SocketAddr)>
+        for Transport<Item, SinkItem, Codec>
+    where
+        SinkItem: Serialize + Unpin,
+        Codec: Serializer<SinkItem, Error = CodecError> + Unpin,
+        CodecError: Into<Box<dyn Error + Send + Sync>>,
+    {
+        type Error = io::Error;
+
+        fn poll_ready(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+            self.poll_flush(cx)
+        }
+
+        fn start_send(
+            self: Pin<&mut Self>,
+            (item, addr): (SinkItem, SocketAddr),
+        ) -> io::Result<()> {
+            let this = self.get_mut();
+            debug_assert!(this.pending.is_none());
+            let bytes = Pin::new(&mut this.codec)
+                .serialize(&item)
+                .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?;
+            this.pending = Some((bytes, addr));
+            Ok(())
+        }
+
+        fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+            let this = self.get_mut();
+            if let Some((bytes, addr)) = &this.pending {
+                futures::ready!(this.socket.poll_send_to(cx, bytes, addr))?;
+                this.pending = None;
+            }
+            Poll::Ready(Ok(()))
+        }
+
+        fn poll_close(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+            self.poll_flush(cx)
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::Transport;
@@ -330,4 +1552,109 @@ mod tests {
         assert_matches!(transport.poll_flush(&mut ctx()), Poll::Ready(Ok(())));
         assert_eq!(writer, b"\x00\x00\x00\x18\"Test one, check check.\"");
     }
+
+    #[test]
+    fn test_poisoned_after_error() {
+        struct TestIo(Cursor<&'static [u8]>);
+
+        impl AsyncRead for TestIo {
+            fn poll_read(
+                mut self: Pin<&mut Self>,
+                cx: &mut Context<'_>,
+                buf: &mut [u8],
+            ) -> Poll<io::Result<usize>> {
+                AsyncRead::poll_read(Pin::new(self.0.get_mut()), cx, buf)
+            }
+        }
+
+        impl AsyncWrite for TestIo {
+            fn poll_write(
+                self: Pin<&mut Self>,
+                _cx: &mut Context<'_>,
+                _buf: &[u8],
+            ) -> Poll<io::Result<usize>> {
+                unreachable!()
+            }
+
+            fn poll_flush(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+                unreachable!()
+            }
+
+            fn poll_shutdown(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+                unreachable!()
+            }
+        }
+
+        // A well-framed message whose payload isn't valid JSON, so the codec (not the framing)
+        // layer is what errors.
+        let data = b"\x00\x00\x00\x01!";
+        let transport = Transport::from((
+            TestIo(Cursor::new(data)),
+            SymmetricalJson::<String>::default(),
+        ));
+        pin_mut!(transport);
+
+        assert_matches!(transport.as_mut().poll_next(&mut ctx()), Poll::Ready(Some(Err(_))));
+
+        // Once poisoned, every subsequent operation returns the same stable error instead of
+        // touching the inner codec again.
+        let poll_next_again = transport.as_mut().poll_next(&mut ctx());
+        match poll_next_again {
+            Poll::Ready(Some(Err(e))) => {
+                assert_eq!(e.kind(), io::ErrorKind::Other);
+                assert_eq!(e.to_string(), "transport poisoned by previous error");
+            }
+            other => panic!("expected a poisoned error, got {:?}", other),
+        }
+        match transport.as_mut().poll_ready(&mut ctx()) {
+            Poll::Ready(Err(e)) => assert_eq!(e.to_string(), "transport poisoned by previous error"),
+            other => panic!("expected a poisoned error, got {:?}", other),
+        }
+        assert_matches!(
+            transport.as_mut().start_send("ignored".into()),
+            Err(ref e) if e.to_string() == "transport poisoned by previous error"
+        );
+    }
+
+    #[test]
+    fn test_builder_rejects_oversized_frame() {
+        struct TestIo(Cursor<&'static [u8]>);
+
+        impl AsyncRead for TestIo {
+            fn poll_read(
+                mut self: Pin<&mut Self>,
+                cx: &mut Context<'_>,
+                buf: &mut [u8],
+            ) -> Poll<io::Result<usize>> {
+                AsyncRead::poll_read(Pin::new(self.0.get_mut()), cx, buf)
+            }
+        }
+
+        impl AsyncWrite for TestIo {
+            fn poll_write(
+                self: Pin<&mut Self>,
+                _cx: &mut Context<'_>,
+                _buf: &[u8],
+            ) -> Poll<io::Result<usize>> {
+                unreachable!()
+            }
+
+            fn poll_flush(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+                unreachable!()
+            }
+
+            fn poll_shutdown(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+                unreachable!()
+            }
+        }
+
+        // Declares a 1000-byte frame, which exceeds the 16-byte cap configured below.
+        let data = b"\x00\x00\x03\xe8";
+        let transport = super::Builder::new()
+            .max_frame_length(16)
+            .build(TestIo(Cursor::new(data)), SymmetricalJson::<String>::default());
+        pin_mut!(transport);
+
+        assert_matches!(transport.as_mut().poll_next(&mut ctx()), Poll::Ready(Some(Err(_))));
+    }
 }